@@ -0,0 +1,17 @@
+// security_tests/fuzz/fuzz_targets/hardware.rs
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use security_tests::{decode, validate_hardware_binding};
+
+// Drive the hardware-profile decoder and binding validator with mutated bytes.
+// The invariant: an adversarial fingerprint never panics, never slices out of
+// bounds, and never binds as a genuine device.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(profile) = decode::decode_hardware(data) {
+        assert!(
+            !validate_hardware_binding(&profile),
+            "arbitrary bytes validated as a genuine hardware binding"
+        );
+    }
+});
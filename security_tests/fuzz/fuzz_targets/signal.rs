@@ -0,0 +1,16 @@
+// security_tests/fuzz/fuzz_targets/signal.rs
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use security_tests::{decode, validate_signal};
+
+// Drive the signal decoder and validator with coverage-guided mutated bytes.
+// The invariant: a structurally invalid signal never panics, never slices out
+// of bounds, and never validates as genuine.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(signal) = decode::decode_signal(data) {
+        // Decoded signals from arbitrary bytes carry no valid proof chain or
+        // signature, so they must never be accepted.
+        assert!(!validate_signal(&signal), "arbitrary bytes validated as a genuine signal");
+    }
+});
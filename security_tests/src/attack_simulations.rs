@@ -1,22 +1,38 @@
-// security_tests/src/attack_simulation.rs
+// security_tests/src/attack_simulations.rs
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use tokio::sync::Notify;
+
+use crate::finality::{FinalityTracker, SignedSignal};
+use crate::{frost, proof_of_history, HardwareProfile, SignalRuntime, SignalState};
+
+/// Absolute minimum sequential-hash count a chain link must carry to be
+/// structurally well-formed, independent of network timing.
+const STRUCTURAL_MIN_TICKS: u64 = 1;
+
 pub struct AttackSimulation {
     attack_type: AttackType,
     config: AttackConfig,
     metrics: MetricsCollector,
 }
 
-#[derive(Debug)]
-enum AttackType {
+#[derive(Debug, Clone, Copy)]
+pub enum AttackType {
     Replay,
     TimeManipulation,
     HardwareSpoofing,
     SignalForging,
     EntropyManipulation,
     StateCorruption,
+    ValidatorEquivocation,
+    ThresholdSubversion,
+    CrashRecovery,
 }
 
 impl AttackSimulation {
-    async fn run(&self) -> SimulationResults {
+    pub async fn run(&self) -> SimulationResults {
         match self.attack_type {
             AttackType::Replay => self.simulate_replay_attack().await,
             AttackType::TimeManipulation => self.simulate_time_manipulation().await,
@@ -24,6 +40,9 @@ impl AttackSimulation {
             AttackType::SignalForging => self.simulate_signal_forging().await,
             AttackType::EntropyManipulation => self.simulate_entropy_manipulation().await,
             AttackType::StateCorruption => self.simulate_state_corruption().await,
+            AttackType::ValidatorEquivocation => self.simulate_validator_equivocation().await,
+            AttackType::ThresholdSubversion => self.simulate_threshold_subversion().await,
+            AttackType::CrashRecovery => self.simulate_crash_recovery().await,
         }
     }
 
@@ -35,39 +54,54 @@ impl AttackSimulation {
         let original_signal = runtime.generate_signal();
         
         // Attempt immediate replay
-        let immediate_replay = self.attempt_replay(&original_signal, Duration::ZERO).await;
+        let immediate_replay = self.attempt_replay(&original_signal).await;
         results.add_attempt("immediate_replay", !immediate_replay);
-        
-        // Attempt delayed replay
-        let delayed_replay = self.attempt_replay(
-            &original_signal,
-            Duration::from_secs(60)
-        ).await;
-        results.add_attempt("delayed_replay", !delayed_replay);
-        
+
         // Attempt modified replay
         let modified_signal = self.modify_signal(&original_signal);
-        let modified_replay = self.attempt_replay(&modified_signal, Duration::ZERO).await;
+        let modified_replay = self.attempt_replay(&modified_signal).await;
         results.add_attempt("modified_replay", !modified_replay);
-        
+
+        // Seal two genuinely different, sequentially chained links: `first`
+        // follows the head, `second` follows `first` and carries a distinct
+        // payload. Each verifies against its real predecessor — this doubles as
+        // the positive control proving `verify` does not simply always fail.
+        let runtime = SignalRuntime::new();
+        let first = runtime.generate_signal();
+        let mut second = first.clone();
+        second.data = b"second-link".to_vec();
+        proof_of_history::seal(&mut second, first.hash, first.tick_count);
+        let in_order = proof_of_history::verify(&first, &first.prev_hash, STRUCTURAL_MIN_TICKS)
+            && proof_of_history::verify(&second, &first.hash, STRUCTURAL_MIN_TICKS);
+        results.add_attempt("in_order_verifies", in_order);
+
+        // Swapping the order breaks the chain: checking `second` against the
+        // head it does not follow, or `first` against `second`'s hash, must
+        // both fail the predecessor link check.
+        let reordered = proof_of_history::verify(&second, &first.prev_hash, STRUCTURAL_MIN_TICKS)
+            || proof_of_history::verify(&first, &second.hash, STRUCTURAL_MIN_TICKS);
+        results.add_attempt("reordered_chain", !reordered);
+
         results
     }
 
     async fn simulate_time_manipulation(&self) -> SimulationResults {
         let mut results = SimulationResults::new("time_manipulation");
-        
-        // Test future timestamps
+
+        // Future-dated timestamps have no defined age, which `validate_signal`
+        // never accepts.
         let future_result = self.test_future_timestamps().await;
         results.add_attempt("future_timestamps", !future_result);
-        
-        // Test past timestamps
+
+        // Timestamps older than the default abandon threshold are rejected.
         let past_result = self.test_past_timestamps().await;
         results.add_attempt("past_timestamps", !past_result);
-        
-        // Test timestamp manipulation during validation
+
+        // A timestamp just past the default valid-age cutoff, but inside the
+        // abandon threshold, still falls outside the adaptive window.
         let validation_result = self.test_validation_timing().await;
         results.add_attempt("validation_timing", !validation_result);
-        
+
         results
     }
 
@@ -101,13 +135,302 @@ impl AttackSimulation {
         let modified_signal = self.modify_signal(&legitimate_signal);
         let mod_result = self.validate_signal(&modified_signal).await;
         results.add_attempt("signal_modification", !mod_result);
-        
+
+        // Positive control: the untampered, in-order signal verifies, proving
+        // the negative `spliced_chain` check below is actually exercising the
+        // chain rather than a `verify` that always fails.
+        results.add_attempt(
+            "genuine_verifies",
+            proof_of_history::verify(
+                &legitimate_signal,
+                &legitimate_signal.prev_hash,
+                STRUCTURAL_MIN_TICKS,
+            ),
+        );
+
+        // Splicing a foreign payload into a valid link leaves the recorded
+        // hash inconsistent with the recomputed chain.
+        let spliced = self.modify_signal(&legitimate_signal);
+        let spliced_chain = proof_of_history::verify(
+            &spliced,
+            &legitimate_signal.prev_hash,
+            STRUCTURAL_MIN_TICKS,
+        );
+        results.add_attempt("spliced_chain", !spliced_chain);
+
+        results
+    }
+
+    async fn simulate_validator_equivocation(&self) -> SimulationResults {
+        let mut results = SimulationResults::new("validator_equivocation");
+
+        // A three-node set needs three distinct signers for finality.
+        let validators = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let mut tracker = FinalityTracker::new(validators, 16);
+
+        // A single validator submits two conflicting signals and then keeps
+        // padding the window under its own identity.
+        let conflicting = [7u8; 32];
+        tracker.admit(SignedSignal { hash: conflicting, signer: validators[0] });
+        tracker.admit(SignedSignal { hash: [8u8; 32], signer: validators[0] });
+        tracker.admit(SignedSignal { hash: [9u8; 32], signer: validators[0] });
+
+        // The distinct-signer count never exceeds one, so finality is never
+        // reached regardless of how many times the equivocator signs.
+        results.add_attempt("equivocation_finality", !tracker.is_final(&conflicting));
+
+        // A key outside the live set cannot contribute to quorum at all.
+        let admitted_foreign = tracker.admit(SignedSignal {
+            hash: conflicting,
+            signer: [99u8; 32],
+        });
+        results.add_attempt("foreign_signer_admitted", !admitted_foreign);
+
+        // Validator-set rotation: a fresh target with two of a three-member
+        // set signing falls short of quorum, and removing the non-signing
+        // third member recomputes the bar against the shrunk live set so the
+        // same two signers now clear it.
+        let rotation_target = [11u8; 32];
+        tracker.admit(SignedSignal { hash: rotation_target, signer: validators[0] });
+        tracker.admit(SignedSignal { hash: [12u8; 32], signer: validators[1] });
+        results.add_attempt("short_of_quorum_before_rotation", !tracker.is_final(&rotation_target));
+        tracker.remove_validator(&validators[2]);
+        results.add_attempt("quorum_recomputed_after_removal", tracker.is_final(&rotation_target));
+
+        results
+    }
+
+    async fn simulate_threshold_subversion(&self) -> SimulationResults {
+        let mut results = SimulationResults::new("threshold_subversion");
+
+        let kg = frost::keygen(2, 3, 0x0a7e);
+        let payload = b"signal-payload";
+
+        // Fewer than `t` partials must not aggregate into a usable signature.
+        let commitments = vec![frost::commit(kg.shares[0].index, 1111)];
+        let partial = frost::sign_partial(
+            &kg.shares[0],
+            &commitments[0],
+            &commitments,
+            kg.group_public,
+            payload,
+        );
+        let short = frost::aggregate(&[partial], &commitments, kg.threshold);
+        results.add_attempt("insufficient_partials", short.is_none());
+
+        // A malformed partial with no matching nonce commitment (a rogue share)
+        // invalidates aggregation.
+        let c0 = frost::commit(kg.shares[0].index, 1111);
+        let c1 = frost::commit(kg.shares[1].index, 2222);
+        let both = vec![c0.clone(), c1.clone()];
+        let p0 = frost::sign_partial(&kg.shares[0], &c0, &both, kg.group_public, payload);
+        let rogue = frost::PartialSignature { index: 404, value: 7 };
+        let subverted = frost::aggregate(&[p0, rogue], &both, kg.threshold);
+        results.add_attempt("rogue_partial", subverted.is_none());
+
+        // A rogue nonce that diverges from the committed one yields a signature
+        // that fails verification against the group key.
+        let p1_ok = frost::sign_partial(&kg.shares[1], &c1, &both, kg.group_public, payload);
+        let p0_ok = frost::sign_partial(&kg.shares[0], &c0, &both, kg.group_public, payload);
+        if let Some(mut sig) = frost::aggregate(&[p0_ok, p1_ok], &both, kg.threshold) {
+            sig.r = sig.r.wrapping_add(1);
+            results.add_attempt("rogue_nonce", !frost::verify(&sig, kg.group_public, payload));
+        } else {
+            results.add_attempt("rogue_nonce", true);
+        }
+
+        results
+    }
+
+    async fn simulate_crash_recovery(&self) -> SimulationResults {
+        let mut results = SimulationResults::new("crash_recovery");
+
+        // The chain head only advances when `validate_signal` durably commits
+        // a signal; `generate_signal` produces a candidate without touching
+        // the chain head. The runtime's commit path parks at the durability
+        // barrier, so the crash lands while a `validate_signal` call is
+        // genuinely in flight — past validation, before the durable write.
+        let recovery_start = Instant::now();
+        let users = self.config.concurrent_users.max(1);
+        let arrived = Arc::new(Notify::new());
+        let release = Arc::new(Notify::new());
+        let runtime = Arc::new(SignalRuntime::with_commit_hook(
+            Arc::clone(&arrived),
+            Arc::clone(&release),
+        ));
+        let committed = runtime.last_committed_hash();
+
+        let mut handles = Vec::new();
+        for _ in 0..users {
+            let runtime = Arc::clone(&runtime);
+            handles.push(tokio::spawn(async move {
+                let signal = runtime.generate_signal();
+                // Enters `validate_signal`, which announces on `arrived` and
+                // then parks after validation succeeds but before the commit.
+                runtime.validate_signal(&signal).await
+            }));
+        }
+        // Wait until a task is parked mid-commit, then inject the crash.
+        // `release` is never notified, so no parked task reaches the durable
+        // write.
+        arrived.notified().await;
+        for handle in &handles {
+            handle.abort();
+        }
+        for handle in handles {
+            // An aborted task yields a cancellation error rather than a result;
+            // an interrupted commit must leave no partially-written state.
+            let _ = handle.await;
+        }
+
+        // No interrupted validation committed, so the durable head is exactly
+        // where it was before the crash.
+        let resumed = runtime.last_committed_hash() == committed;
+        results.add_attempt("chain_resumes_from_commit", resumed);
+
+        // A node restarted from the durable head accepts new work and makes
+        // progress, proving recovery left the chain consistent.
+        let recovered = SignalRuntime::recovered_from(committed);
+        let head = recovered.last_committed_hash();
+        let post = recovered.generate_signal();
+        let post_ok = recovered.validate_signal(&post).await
+            && proof_of_history::verify(&post, &head, STRUCTURAL_MIN_TICKS);
+        results.add_attempt("consistent_after_recovery", post_ok);
+
+        self.metrics
+            .record_recovery_latency(recovery_start.elapsed());
+
+        results
+    }
+}
+
+impl AttackSimulation {
+    async fn simulate_entropy_manipulation(&self) -> SimulationResults {
+        let mut results = SimulationResults::new("entropy_manipulation");
+
+        // Flipping the entropy state without re-sealing leaves the signature
+        // and chain hash inconsistent, so validation must reject it.
+        let runtime = SignalRuntime::new();
+        let signal = runtime.generate_signal();
+        let mut tampered = signal.clone();
+        tampered.entropy_state = tampered.entropy_state.wrapping_add(1);
+        let accepted = self.validate_signal(&tampered).await;
+        results.add_attempt("entropy_tamper", !accepted);
+
+        results
+    }
+
+    async fn simulate_state_corruption(&self) -> SimulationResults {
+        let mut results = SimulationResults::new("state_corruption");
+
+        // A single bit-flip in the recorded hash breaks the recomputed chain.
+        let runtime = SignalRuntime::new();
+        let signal = runtime.generate_signal();
+        let mut corrupted = signal.clone();
+        corrupted.hash[0] ^= 0x01;
+        let accepted = self.validate_signal(&corrupted).await;
+        results.add_attempt("bit_flip", !accepted);
+
         results
     }
 }
 
 // Attack utilities
 impl AttackSimulation {
+    pub fn new(attack_type: AttackType) -> Self {
+        Self {
+            attack_type,
+            config: AttackConfig::default(),
+            metrics: MetricsCollector::new(),
+        }
+    }
+
+    /// Submit a signal to a fresh runtime, accept it once, then replay the
+    /// identical signal. Returns whether the replay was (wrongly) accepted a
+    /// second time. A stale-timestamp replay is covered separately by
+    /// `simulate_time_manipulation`, which drives the temporal window itself
+    /// rather than a second submission of an unmodified signal (which is
+    /// always rejected here on the chain-link check alone, regardless of any
+    /// elapsed delay).
+    async fn attempt_replay(&self, signal: &SignalState) -> bool {
+        let runtime = SignalRuntime::new();
+        // First submission may legitimately advance the chain head.
+        let _ = runtime.validate_signal(signal).await;
+        // The replay links to a head that has already moved on.
+        runtime.validate_signal(signal).await
+    }
+
+    /// Validate a signal against a fresh runtime, returning whether it is
+    /// accepted as genuine.
+    async fn validate_signal(&self, signal: &SignalState) -> bool {
+        SignalRuntime::new().validate_signal(signal).await
+    }
+
+    /// Validate a hardware profile against a fresh runtime.
+    async fn validate_hardware(&self, profile: &HardwareProfile) -> bool {
+        SignalRuntime::new().validate_hardware_binding(profile).await
+    }
+
+    fn modify_hardware_profile(&self, profile: &HardwareProfile) -> HardwareProfile {
+        let mut modified = profile.clone();
+        modified.fingerprint[0] ^= 0xff;
+        modified
+    }
+
+    /// Build a signal sealed and signed exactly like a genuine one from
+    /// `runtime`, except `timestamp` is shifted by `offset_nanos` (negative
+    /// for a stale signal, positive for a future-dated one). Self-consistent
+    /// signature and chain hash, so a skewed signal is rejected by the
+    /// temporal window itself rather than by a structural mismatch.
+    fn build_skewed_signal(&self, runtime: &SignalRuntime, offset_nanos: i128) -> SignalState {
+        let prev_hash = runtime.last_committed_hash();
+        let ticks = runtime.tick_floor();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as i128;
+        let timestamp = (now + offset_nanos).max(0) as u128;
+        let entropy_state = rand::random();
+        let data = vec![0u8; 32];
+        let mut signal = SignalState {
+            timestamp,
+            entropy_state,
+            data,
+            signature: Vec::new(),
+            prev_hash,
+            tick_count: ticks,
+            hash: [0u8; 32],
+        };
+        proof_of_history::seal(&mut signal, prev_hash, ticks);
+        signal.signature =
+            crate::sign_payload(signal.timestamp, signal.entropy_state, &signal.data, &signal.hash);
+        signal
+    }
+
+    /// A future-dated signal presents an age `validate_signal` treats as
+    /// undefined (clock skew or fabrication), so it is rejected outright.
+    async fn test_future_timestamps(&self) -> bool {
+        let runtime = SignalRuntime::new();
+        let signal = self.build_skewed_signal(&runtime, 5_000_000_000);
+        runtime.validate_signal(&signal).await
+    }
+
+    /// A signal stale well past the default abandon threshold is rejected by
+    /// the adaptive window.
+    async fn test_past_timestamps(&self) -> bool {
+        let runtime = SignalRuntime::new();
+        let signal = self.build_skewed_signal(&runtime, -10_000_000_000);
+        runtime.validate_signal(&signal).await
+    }
+
+    /// A signal just past the default valid-age cutoff, but inside the
+    /// abandon threshold, still falls outside the adaptive window.
+    async fn test_validation_timing(&self) -> bool {
+        let runtime = SignalRuntime::new();
+        let signal = self.build_skewed_signal(&runtime, -500_000_000);
+        runtime.validate_signal(&signal).await
+    }
+
     fn modify_signal(&self, signal: &SignalState) -> SignalState {
         let mut modified = signal.clone();
         
@@ -125,6 +448,7 @@ impl AttackSimulation {
             fingerprint: [0u8; 32],
             features: HashSet::new(),
             capabilities: HashMap::new(),
+            attestation: [0u8; 32],
         }
     }
 
@@ -137,24 +461,115 @@ impl AttackSimulation {
             entropy_state: rand::random(),
             data: vec![0u8; 32],
             signature: vec![0u8; 64],
+            // A forged signal carries no genuine chain link: an all-zero
+            // predecessor and a bogus hash that `proof_of_history::verify`
+            // will recompute and reject.
+            prev_hash: [0u8; 32],
+            tick_count: 0,
+            hash: [0u8; 32],
+        }
+    }
+}
+
+/// Tunables for an attack run.
+#[derive(Debug, Clone)]
+struct AttackConfig {
+    concurrent_users: usize,
+}
+
+impl Default for AttackConfig {
+    fn default() -> Self {
+        Self {
+            concurrent_users: 8,
         }
     }
 }
 
+/// Collects timing metrics emitted during a simulation.
+#[derive(Debug, Clone, Default)]
+struct MetricsCollector {
+    recovery_latency: Arc<std::sync::Mutex<Option<Duration>>>,
+}
+
+impl MetricsCollector {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record how long recovery from an injected crash took.
+    fn record_recovery_latency(&self, latency: Duration) {
+        *self.recovery_latency.lock().unwrap() = Some(latency);
+    }
+}
+
 #[derive(Debug)]
-struct SimulationResults {
+pub struct SimulationResults {
     test_name: String,
     attempts: Vec<AttemptResult>,
     timing: Duration,
 }
 
+impl SimulationResults {
+    fn new(test_name: &str) -> Self {
+        Self {
+            test_name: test_name.to_string(),
+            attempts: Vec::new(),
+            timing: Duration::ZERO,
+        }
+    }
+
+    /// Record one attack attempt. `defended` is true when the attack was
+    /// prevented; the stored `success` is the attacker's outcome, so a
+    /// defended attempt has `success == false`.
+    fn add_attempt(&mut self, name: &str, defended: bool) {
+        self.attempts.push(AttemptResult {
+            name: name.to_string(),
+            success: !defended,
+            error: None,
+        });
+    }
+
+    /// Name of the simulation these results belong to.
+    pub fn test_name(&self) -> &str {
+        &self.test_name
+    }
+
+    /// The recorded attack attempts.
+    pub fn attempts(&self) -> &[AttemptResult] {
+        &self.attempts
+    }
+
+    /// Timing recorded for the simulation. `run` does not measure it, so it
+    /// is `Duration::ZERO` for every simulation in this module.
+    pub fn timing(&self) -> Duration {
+        self.timing
+    }
+}
+
 #[derive(Debug)]
-struct AttemptResult {
+pub struct AttemptResult {
     name: String,
     success: bool,
     error: Option<String>,
 }
 
+impl AttemptResult {
+    /// Name of the attempt.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the attack succeeded (true) or was prevented (false).
+    pub fn succeeded(&self) -> bool {
+        self.success
+    }
+
+    /// Any error recorded for the attempt.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,6 +588,32 @@ mod tests {
         }
     }
 
+    /// Replay any crashing inputs the fuzzer has promoted to regression seeds
+    /// under `fuzz/corpus/signal/`. Each retained file becomes a permanent test
+    /// vector: re-decoding and re-validating it must never panic and must
+    /// never report a structurally invalid signal as genuine. Seeds of the
+    /// `hardware` target are not replayed here.
+    #[tokio::test]
+    async fn test_fuzz_regression_seeds() {
+        let seeds = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/corpus/signal");
+        let entries = match std::fs::read_dir(&seeds) {
+            Ok(entries) => entries,
+            Err(_) => return, // No corpus yet: nothing to replay.
+        };
+        for entry in entries.flatten() {
+            let bytes = std::fs::read(entry.path()).unwrap_or_default();
+            if let Ok(signal) = crate::decode::decode_signal(&bytes) {
+                assert!(
+                    !AttackSimulation::new(AttackType::SignalForging)
+                        .validate_signal(&signal)
+                        .await,
+                    "regression seed {:?} validated as a genuine signal",
+                    entry.path()
+                );
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_multiple_attack_vectors() {
         let attacks = vec![
@@ -180,6 +621,9 @@ mod tests {
             AttackType::TimeManipulation,
             AttackType::HardwareSpoofing,
             AttackType::SignalForging,
+            AttackType::ValidatorEquivocation,
+            AttackType::ThresholdSubversion,
+            AttackType::CrashRecovery,
         ];
 
         for attack_type in attacks {
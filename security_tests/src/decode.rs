@@ -0,0 +1,147 @@
+// security_tests/src/decode.rs
+use std::collections::{HashMap, HashSet};
+
+use super::{HardwareProfile, SignalState};
+
+/// Reason a byte buffer failed to decode into a structured value.
+///
+/// Decoders return this rather than panicking or slicing out of bounds so the
+/// fuzz targets can assert that malformed input is always rejected cleanly.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    Truncated,
+    Oversized,
+    Trailing,
+}
+
+/// Cursor over a byte slice with checked reads; never indexes past the end.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        let end = self.pos.checked_add(n).ok_or(DecodeError::Oversized)?;
+        let slice = self.bytes.get(self.pos..end).ok_or(DecodeError::Truncated)?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn u16(&mut self) -> Result<u16, DecodeError> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    fn u128(&mut self) -> Result<u128, DecodeError> {
+        Ok(u128::from_le_bytes(self.array()?))
+    }
+}
+
+/// Upper bound on any length-prefixed field, so a hostile length prefix cannot
+/// request a multi-gigabyte allocation.
+const MAX_FIELD: usize = 64 * 1024;
+
+/// Decode a `SignalState` from its wire form. Every length is bounds-checked
+/// against the remaining buffer before any allocation.
+pub fn decode_signal(bytes: &[u8]) -> Result<SignalState, DecodeError> {
+    let mut r = Reader::new(bytes);
+    let timestamp = r.u128()?;
+    let entropy_state = r.u128()?;
+    let prev_hash = r.array::<32>()?;
+    let tick_count = r.u64()?;
+    let hash = r.array::<32>()?;
+
+    let data_len = r.u16()? as usize;
+    if data_len > MAX_FIELD {
+        return Err(DecodeError::Oversized);
+    }
+    let data = r.take(data_len)?.to_vec();
+
+    let sig_len = r.u16()? as usize;
+    if sig_len > MAX_FIELD {
+        return Err(DecodeError::Oversized);
+    }
+    let signature = r.take(sig_len)?.to_vec();
+
+    if r.pos != bytes.len() {
+        return Err(DecodeError::Trailing);
+    }
+
+    Ok(SignalState {
+        timestamp,
+        entropy_state,
+        data,
+        signature,
+        prev_hash,
+        tick_count,
+        hash,
+    })
+}
+
+/// Decode a `HardwareProfile` from its wire form.
+pub fn decode_hardware(bytes: &[u8]) -> Result<HardwareProfile, DecodeError> {
+    let mut r = Reader::new(bytes);
+    let fingerprint = r.array::<32>()?;
+
+    let feature_count = r.u16()? as usize;
+    let mut features = HashSet::with_capacity(feature_count.min(MAX_FIELD));
+    for _ in 0..feature_count {
+        features.insert(r.u64()?);
+    }
+
+    let cap_count = r.u16()? as usize;
+    let mut capabilities = HashMap::with_capacity(cap_count.min(MAX_FIELD));
+    for _ in 0..cap_count {
+        let key = r.u64()?;
+        let value = r.u64()?;
+        capabilities.insert(key, value);
+    }
+
+    let attestation = r.array::<32>()?;
+
+    if r.pos != bytes.len() {
+        return Err(DecodeError::Trailing);
+    }
+
+    Ok(HardwareProfile {
+        fingerprint,
+        features,
+        capabilities,
+        attestation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncated_signal_is_rejected() {
+        assert_eq!(decode_signal(&[0u8; 4]), Err(DecodeError::Truncated));
+    }
+
+    #[test]
+    fn oversized_length_prefix_is_rejected() {
+        // Valid fixed header, then a data length prefix far beyond the buffer.
+        let mut bytes = vec![0u8; 16 + 16 + 32 + 8 + 32];
+        bytes.extend_from_slice(&0xffffu16.to_le_bytes());
+        assert!(matches!(
+            decode_signal(&bytes),
+            Err(DecodeError::Oversized | DecodeError::Truncated)
+        ));
+    }
+}
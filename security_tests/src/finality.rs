@@ -0,0 +1,150 @@
+// security_tests/src/finality.rs
+use std::collections::{HashSet, VecDeque};
+
+/// Identity of a validator, keyed by its public key bytes.
+pub type ValidatorId = [u8; 32];
+
+/// A signal as it enters the finality window: the chain hash it commits to
+/// plus the validator that signed it.
+#[derive(Debug, Clone)]
+pub struct SignedSignal {
+    pub hash: [u8; 32],
+    pub signer: ValidatorId,
+}
+
+/// Rolling-finality quorum over a sliding window of recently-signed signals.
+///
+/// A signal is final once the set of *distinct* validators that signed it or a
+/// later signal exceeds two-thirds of the live validator set. The set rotates
+/// in and out of scope and the quorum is always recomputed against the current
+/// membership, so adding a key immediately raises the bar and removing one
+/// lowers it.
+#[derive(Debug)]
+pub struct FinalityTracker {
+    validators: HashSet<ValidatorId>,
+    window: VecDeque<SignedSignal>,
+    capacity: usize,
+}
+
+impl FinalityTracker {
+    pub fn new(validators: impl IntoIterator<Item = ValidatorId>, window: usize) -> Self {
+        Self {
+            validators: validators.into_iter().collect(),
+            window: VecDeque::with_capacity(window),
+            capacity: window,
+        }
+    }
+
+    /// Quorum size: strictly more than two-thirds of the live validator set.
+    fn quorum(&self) -> usize {
+        (self.validators.len() * 2) / 3 + 1
+    }
+
+    /// Admit a newly-signed signal. Signals from keys outside the current set
+    /// are rejected outright.
+    pub fn admit(&mut self, signal: SignedSignal) -> bool {
+        if !self.validators.contains(&signal.signer) {
+            return false;
+        }
+        if self.window.len() == self.capacity {
+            self.window.pop_front();
+        }
+        self.window.push_back(signal);
+        true
+    }
+
+    /// Rotate the validator set; the quorum is recomputed against the live set
+    /// on the next query.
+    pub fn add_validator(&mut self, id: ValidatorId) {
+        self.validators.insert(id);
+    }
+
+    pub fn remove_validator(&mut self, id: &ValidatorId) {
+        self.validators.remove(id);
+    }
+
+    /// Whether the signal recorded at `hash` has reached rolling finality.
+    ///
+    /// Scans backward from the newest signal, collecting distinct in-set
+    /// validators, until it passes the target hash. A single key signing twice
+    /// in the window counts once, so equivocation cannot manufacture a quorum.
+    pub fn is_final(&self, hash: &[u8; 32]) -> bool {
+        let mut distinct: HashSet<ValidatorId> = HashSet::new();
+        let mut reached = false;
+        for signal in self.window.iter().rev() {
+            if self.validators.contains(&signal.signer) {
+                distinct.insert(signal.signer);
+            }
+            if &signal.hash == hash {
+                reached = true;
+                break;
+            }
+        }
+        reached && distinct.len() >= self.quorum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(n: u8) -> ValidatorId {
+        [n; 32]
+    }
+
+    #[test]
+    fn reaches_finality_with_two_thirds_distinct_signers() {
+        let mut tracker = FinalityTracker::new([id(1), id(2), id(3)], 16);
+        let target = [9u8; 32];
+        tracker.admit(SignedSignal { hash: target, signer: id(1) });
+        tracker.admit(SignedSignal { hash: [10u8; 32], signer: id(2) });
+        tracker.admit(SignedSignal { hash: [11u8; 32], signer: id(3) });
+        assert!(tracker.is_final(&target));
+    }
+
+    #[test]
+    fn equivocation_never_reaches_finality() {
+        let mut tracker = FinalityTracker::new([id(1), id(2), id(3)], 16);
+        let target = [9u8; 32];
+        tracker.admit(SignedSignal { hash: target, signer: id(1) });
+        // Same validator signs repeatedly; distinct count stays at one.
+        tracker.admit(SignedSignal { hash: [10u8; 32], signer: id(1) });
+        tracker.admit(SignedSignal { hash: [11u8; 32], signer: id(1) });
+        assert!(!tracker.is_final(&target));
+    }
+
+    #[test]
+    fn rejects_keys_outside_the_set() {
+        let mut tracker = FinalityTracker::new([id(1), id(2)], 16);
+        assert!(!tracker.admit(SignedSignal { hash: [9u8; 32], signer: id(99) }));
+    }
+
+    #[test]
+    fn removing_a_validator_lowers_the_live_quorum() {
+        let mut tracker = FinalityTracker::new([id(1), id(2), id(3)], 16);
+        let target = [9u8; 32];
+        tracker.admit(SignedSignal { hash: target, signer: id(1) });
+        tracker.admit(SignedSignal { hash: [10u8; 32], signer: id(2) });
+        assert!(!tracker.is_final(&target));
+
+        // Two distinct signers fall short of the three a set of three needs;
+        // shrinking the live set to two recomputes the bar so the same two
+        // signers now clear it.
+        tracker.remove_validator(&id(3));
+        assert!(tracker.is_final(&target));
+    }
+
+    #[test]
+    fn adding_a_validator_raises_the_live_quorum() {
+        let mut tracker = FinalityTracker::new([id(1)], 16);
+        let target = [9u8; 32];
+        tracker.admit(SignedSignal { hash: target, signer: id(1) });
+        assert!(tracker.is_final(&target));
+
+        // The lone signer was a quorum of one; growing the live set to two
+        // recomputes the bar against the new membership and the same signer
+        // no longer clears it alone.
+        tracker.add_validator(id(2));
+        assert!(!tracker.is_final(&target));
+    }
+}
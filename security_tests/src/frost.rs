@@ -0,0 +1,321 @@
+// security_tests/src/frost.rs
+//
+// SIMULATION-ONLY threshold-signing stand-in. The arithmetic below is a faithful
+// model of FROST's t-of-n structure — Shamir shares, two-round commit/partial
+// sign, Lagrange aggregation into one group signature — but it runs over a 61-bit
+// additive group with a trivially invertible "public key" and caller-supplied
+// deterministic nonces. It has ZERO cryptographic security: the discrete log is
+// solvable by inspection and nonce reuse leaks shares. It exists so the
+// `ThresholdSubversion` attack simulation can exercise the aggregation/verification
+// logic. It is NOT a key-compromise mitigation and MUST NOT sign real signals;
+// a production signer binds this protocol to the actual signature group with
+// CSPRNG nonces.
+use std::collections::BTreeMap;
+
+/// Prime modulus of the scalar field. Mersenne prime, keeps products in u128.
+const Q: u128 = (1 << 61) - 1;
+
+/// Fixed base the additive "public key" is derived from. In a real group this
+/// would be a curve generator and the map below a scalar multiplication; here it
+/// is ordinary modular multiplication, which is why the construction is a mock.
+const G: u128 = 5;
+
+fn mulmod(a: u128, b: u128) -> u128 {
+    (a % Q) * (b % Q) % Q
+}
+
+fn addmod(a: u128, b: u128) -> u128 {
+    (a % Q + b % Q) % Q
+}
+
+fn submod(a: u128, b: u128) -> u128 {
+    (a % Q + Q - b % Q) % Q
+}
+
+fn powmod(mut base: u128, mut exp: u128) -> u128 {
+    let mut acc = 1u128;
+    base %= Q;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = mulmod(acc, base);
+        }
+        base = mulmod(base, base);
+        exp >>= 1;
+    }
+    acc
+}
+
+fn invmod(a: u128) -> u128 {
+    powmod(a, Q - 2)
+}
+
+/// One participant's long-lived secret share of the group key.
+#[derive(Debug, Clone)]
+pub struct KeyShare {
+    pub index: u128,
+    pub secret: u128,
+}
+
+/// A round-one nonce commitment published before any partial signature.
+#[derive(Debug, Clone)]
+pub struct NonceCommitment {
+    pub index: u128,
+    nonce: u128,
+    pub commitment: u128,
+}
+
+/// A round-two partial signature over the payload and aggregate nonce.
+#[derive(Debug, Clone)]
+pub struct PartialSignature {
+    pub index: u128,
+    pub value: u128,
+}
+
+/// The aggregated group signature, verifiable against the single group key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupSignature {
+    pub r: u128,
+    pub s: u128,
+}
+
+/// Output of key generation: the public group key plus `n` secret shares.
+pub struct KeyGen {
+    pub group_public: u128,
+    pub shares: Vec<KeyShare>,
+    pub threshold: usize,
+}
+
+/// Split a group key into `n` Schnorr shares with recovery threshold `t` using
+/// a degree `t-1` Shamir polynomial. `seed` substitutes for the CSPRNG that a
+/// real implementation would draw coefficients from.
+pub fn keygen(t: usize, n: usize, seed: u128) -> KeyGen {
+    let mut coeffs = Vec::with_capacity(t);
+    let mut acc = seed % Q;
+    for _ in 0..t {
+        acc = addmod(mulmod(acc, 6_364_136_223_846_793_005 % Q), 1);
+        coeffs.push(acc);
+    }
+    let group_secret = coeffs[0];
+
+    let mut shares = Vec::with_capacity(n);
+    for i in 1..=n as u128 {
+        let mut y = 0u128;
+        let mut x_pow = 1u128;
+        for c in &coeffs {
+            y = addmod(y, mulmod(*c, x_pow));
+            x_pow = mulmod(x_pow, i);
+        }
+        shares.push(KeyShare { index: i, secret: y });
+    }
+
+    KeyGen {
+        group_public: mulmod(G, group_secret),
+        shares,
+        threshold: t,
+    }
+}
+
+/// Lagrange coefficient for participant `i` over the signing set `indices`,
+/// evaluated at zero.
+fn lagrange(i: u128, indices: &[u128]) -> u128 {
+    let mut num = 1u128;
+    let mut den = 1u128;
+    for &j in indices {
+        if j != i {
+            num = mulmod(num, j);
+            den = mulmod(den, submod(j, i));
+        }
+    }
+    mulmod(num, invmod(den))
+}
+
+/// Round one: a participant publishes a nonce commitment.
+pub fn commit(index: u128, nonce: u128) -> NonceCommitment {
+    NonceCommitment {
+        index,
+        nonce: nonce % Q,
+        commitment: mulmod(G, nonce),
+    }
+}
+
+/// Fiat-Shamir challenge binding the aggregate nonce, group key, and payload.
+fn challenge(agg_nonce: u128, group_public: u128, payload: &[u8]) -> u128 {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&agg_nonce.to_le_bytes());
+    hasher.update(&group_public.to_le_bytes());
+    hasher.update(payload);
+    let bytes = hasher.finalize();
+    let mut x = [0u8; 16];
+    x.copy_from_slice(&bytes.as_bytes()[..16]);
+    u128::from_le_bytes(x) % Q
+}
+
+/// Aggregate nonce `R` is the sum of the round-one commitments (the additive
+/// analogue of the product of `G^{k_i}` in a multiplicative group).
+pub fn aggregate_nonce(commitments: &[NonceCommitment]) -> u128 {
+    commitments
+        .iter()
+        .fold(0u128, |acc, c| addmod(acc, c.commitment))
+}
+
+/// Round two: a participant produces its partial signature.
+pub fn sign_partial(
+    share: &KeyShare,
+    nonce: &NonceCommitment,
+    commitments: &[NonceCommitment],
+    group_public: u128,
+    payload: &[u8],
+) -> PartialSignature {
+    let indices: Vec<u128> = commitments.iter().map(|c| c.index).collect();
+    let r = aggregate_nonce(commitments);
+    let c = challenge(r, group_public, payload);
+    let lambda = lagrange(share.index, &indices);
+    // z_i = k_i + c * lambda_i * x_i
+    let value = addmod(nonce.nonce, mulmod(c, mulmod(lambda, share.secret)));
+    PartialSignature {
+        index: share.index,
+        value,
+    }
+}
+
+/// Combine `t` partial signatures into one group signature. Fails (returns
+/// `None`) when fewer than `threshold` partials are supplied or when any
+/// partial references a participant without a matching nonce commitment — the
+/// checks that catch a short set or a malformed/rogue share.
+pub fn aggregate(
+    partials: &[PartialSignature],
+    commitments: &[NonceCommitment],
+    threshold: usize,
+) -> Option<GroupSignature> {
+    if partials.len() < threshold {
+        return None;
+    }
+    let by_index: BTreeMap<u128, &NonceCommitment> =
+        commitments.iter().map(|c| (c.index, c)).collect();
+
+    let mut s = 0u128;
+    for p in partials {
+        // A partial whose index has no committed nonce is a rogue/malformed
+        // share and invalidates the aggregate.
+        by_index.get(&p.index)?;
+        s = addmod(s, p.value);
+    }
+    Some(GroupSignature {
+        r: aggregate_nonce(commitments),
+        s,
+    })
+}
+
+/// Verify an aggregate signature against the single group public key. The
+/// additive analogue of the Schnorr check `G^s == R * Y^c` is
+/// `s*G == R + c*Y`.
+pub fn verify(sig: &GroupSignature, group_public: u128, payload: &[u8]) -> bool {
+    let c = challenge(sig.r, group_public, payload);
+    mulmod(G, sig.s) == addmod(sig.r, mulmod(c, group_public))
+}
+
+/// Derive a deterministic per-participant nonce from its index and the
+/// payload, standing in for the CSPRNG draw a production signer would make.
+/// Binding the nonce to the index keeps distinct participants from reusing
+/// one nonce, which is what leaks shares (see module docs).
+fn derive_nonce(index: u128, payload: &[u8]) -> u128 {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(b"0auth3-frost-nonce");
+    hasher.update(&index.to_le_bytes());
+    hasher.update(payload);
+    let bytes = hasher.finalize();
+    let mut x = [0u8; 16];
+    x.copy_from_slice(&bytes.as_bytes()[..16]);
+    u128::from_le_bytes(x) % Q
+}
+
+/// Run a full two-round signing session over `shares` (at least `threshold`
+/// of them) and return the aggregated group signature, or `None` if fewer
+/// than `threshold` shares were supplied. Lets a caller outside this module
+/// (the signal runtime) request one aggregate signature without hand-rolling
+/// the commit/partial/aggregate choreography.
+pub fn sign_group(
+    shares: &[KeyShare],
+    group_public: u128,
+    threshold: usize,
+    payload: &[u8],
+) -> Option<GroupSignature> {
+    let commitments: Vec<NonceCommitment> = shares
+        .iter()
+        .map(|s| commit(s.index, derive_nonce(s.index, payload)))
+        .collect();
+    let partials: Vec<PartialSignature> = shares
+        .iter()
+        .zip(&commitments)
+        .map(|(s, c)| sign_partial(s, c, &commitments, group_public, payload))
+        .collect();
+    aggregate(&partials, &commitments, threshold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn full_session(signers: &[usize], t: usize, payload: &[u8]) -> Option<GroupSignature> {
+        let kg = keygen(t, 3, 0x1234_5678);
+        let commitments: Vec<NonceCommitment> = signers
+            .iter()
+            .map(|&i| commit(kg.shares[i].index, 1000 + i as u128))
+            .collect();
+        let partials: Vec<PartialSignature> = signers
+            .iter()
+            .enumerate()
+            .map(|(pos, &i)| {
+                sign_partial(
+                    &kg.shares[i],
+                    &commitments[pos],
+                    &commitments,
+                    kg.group_public,
+                    payload,
+                )
+            })
+            .collect();
+        aggregate(&partials, &commitments, kg.threshold)
+    }
+
+    #[test]
+    fn t_of_n_signature_verifies() {
+        let kg = keygen(2, 3, 0x1234_5678);
+        let payload = b"signal";
+        let sig = full_session(&[0, 1], 2, payload).expect("aggregation");
+        assert!(verify(&sig, kg.group_public, payload));
+    }
+
+    #[test]
+    fn fewer_than_t_partials_fail() {
+        // Only one partial supplied for a 2-of-3 group.
+        assert!(full_session(&[0], 2, b"signal").is_none());
+    }
+
+    #[test]
+    fn sign_group_produces_verifiable_aggregate() {
+        let kg = keygen(2, 3, 0x1234_5678);
+        let payload = b"signal";
+        let sig = sign_group(&kg.shares[..2], kg.group_public, kg.threshold, payload)
+            .expect("aggregation");
+        assert!(verify(&sig, kg.group_public, payload));
+    }
+
+    #[test]
+    fn sign_group_with_too_few_shares_fails() {
+        let kg = keygen(2, 3, 0x1234_5678);
+        assert!(sign_group(&kg.shares[..1], kg.group_public, kg.threshold, b"signal").is_none());
+    }
+
+    #[test]
+    fn rogue_partial_without_commitment_fails() {
+        let kg = keygen(2, 3, 0x1234_5678);
+        let payload = b"signal";
+        let commitments = vec![commit(kg.shares[0].index, 1000)];
+        let partials = vec![
+            sign_partial(&kg.shares[0], &commitments[0], &commitments, kg.group_public, payload),
+            PartialSignature { index: 999, value: 42 },
+        ];
+        assert!(aggregate(&partials, &commitments, kg.threshold).is_none());
+    }
+}
@@ -0,0 +1,332 @@
+// security_tests/src/lib.rs
+//
+// Crate root for the security test-vector and attack-simulation suite. The
+// shared signal/hardware types and the single signing runtime live here so the
+// attack simulations, the decoders, and the out-of-process fuzz targets all
+// resolve against one public surface.
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use tokio::sync::Notify;
+
+pub mod decode;
+pub mod finality;
+pub mod frost;
+pub mod proof_of_history;
+pub mod timeout_estimator;
+
+pub mod attack_simulations;
+
+use timeout_estimator::TimeoutEstimator;
+
+/// A single link in the proof-of-history signal chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalState {
+    pub timestamp: u128,
+    pub entropy_state: u128,
+    pub data: Vec<u8>,
+    pub signature: Vec<u8>,
+    pub prev_hash: [u8; 32],
+    pub tick_count: u64,
+    pub hash: [u8; 32],
+}
+
+/// A device fingerprint plus the feature/capability set it is bound to, and the
+/// attestation that binds them together.
+#[derive(Debug, Clone)]
+pub struct HardwareProfile {
+    pub fingerprint: [u8; 32],
+    pub features: HashSet<u64>,
+    pub capabilities: HashMap<u64, u64>,
+    /// Binding attestation over the fingerprint and feature/capability set,
+    /// recomputed during validation. A spoofed profile cannot forge it without
+    /// a Blake3 preimage.
+    pub attestation: [u8; 32],
+}
+
+/// Minimum sequential-hash count a signal must carry; the floor used until
+/// the estimator has observed enough samples to derive one.
+const DEFAULT_TICK_FLOOR: u64 = 1 << 16;
+
+/// Fixed t-of-n shape of the signing group. `GROUP_SEED` stands in for the
+/// distributed key-generation ceremony a production deployment would run
+/// once and never re-derive; every runtime and the free-standing fuzz
+/// validator recompute the same shares from it so a signal signed by one
+/// call site verifies against any other.
+const GROUP_THRESHOLD: usize = 2;
+const GROUP_PARTICIPANTS: usize = 3;
+const GROUP_SEED: u128 = 0x0a7e_5196_a174_3e02;
+
+/// The signing group's shares and public key, as produced by the (simulated)
+/// key-generation ceremony. See [`frost`] module docs: this is an arithmetic
+/// stand-in with zero cryptographic security, not a production signer.
+fn group_key() -> frost::KeyGen {
+    frost::keygen(GROUP_THRESHOLD, GROUP_PARTICIPANTS, GROUP_SEED)
+}
+
+/// Serialize the fields a signal's signature covers, in a fixed field order
+/// so signing and verification hash identical bytes.
+fn signal_payload(timestamp: u128, entropy_state: u128, data: &[u8], hash: &[u8; 32]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(16 + 16 + data.len() + 32);
+    payload.extend_from_slice(&timestamp.to_le_bytes());
+    payload.extend_from_slice(&entropy_state.to_le_bytes());
+    payload.extend_from_slice(data);
+    payload.extend_from_slice(hash);
+    payload
+}
+
+/// Sign a signal's payload with the group's threshold signature so the
+/// verifier checks one aggregate signature, never an individual share.
+fn sign_payload(timestamp: u128, entropy_state: u128, data: &[u8], hash: &[u8; 32]) -> Vec<u8> {
+    let kg = group_key();
+    let payload = signal_payload(timestamp, entropy_state, data, hash);
+    let sig = frost::sign_group(&kg.shares[..kg.threshold], kg.group_public, kg.threshold, &payload)
+        .expect("group_key always yields at least `threshold` shares");
+    let mut bytes = Vec::with_capacity(32);
+    bytes.extend_from_slice(&sig.r.to_le_bytes()[..16]);
+    bytes.extend_from_slice(&sig.s.to_le_bytes()[..16]);
+    bytes
+}
+
+/// Decode a signature produced by [`sign_payload`] back into its aggregate
+/// `(r, s)` pair, or `None` if it isn't the expected 32-byte encoding.
+fn decode_group_signature(signature: &[u8]) -> Option<frost::GroupSignature> {
+    if signature.len() != 32 {
+        return None;
+    }
+    let mut r = [0u8; 16];
+    let mut s = [0u8; 16];
+    r.copy_from_slice(&signature[..16]);
+    s.copy_from_slice(&signature[16..]);
+    Some(frost::GroupSignature {
+        r: u128::from_le_bytes(r),
+        s: u128::from_le_bytes(s),
+    })
+}
+
+/// Recompute the binding attestation over a hardware profile. A genuine
+/// enrolment attests `fingerprint || features || capabilities`; a profile that
+/// changes any of them no longer matches. Stands in for the device-attestation
+/// signature a production enrolment would check against a registry.
+fn attest_hardware(profile: &HardwareProfile) -> [u8; 32] {
+    let mut features: Vec<u64> = profile.features.iter().copied().collect();
+    features.sort_unstable();
+    let mut capabilities: Vec<(u64, u64)> =
+        profile.capabilities.iter().map(|(k, v)| (*k, *v)).collect();
+    capabilities.sort_unstable();
+
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(b"0auth3-hardware-binding");
+    hasher.update(&profile.fingerprint);
+    for feature in features {
+        hasher.update(&feature.to_le_bytes());
+    }
+    for (key, value) in capabilities {
+        hasher.update(&key.to_le_bytes());
+        hasher.update(&value.to_le_bytes());
+    }
+    *hasher.finalize().as_bytes()
+}
+
+/// Crash-injection hook for the recovery harness: a validating task announces
+/// on `arrived` that it has reached the commit point — after the signal has
+/// passed validation but before the durable write — then parks on `release`.
+/// The harness waits for an arrival and then aborts, so the crash is injected
+/// with a commit genuinely in flight.
+#[derive(Debug)]
+struct CommitHook {
+    arrived: Arc<Notify>,
+    release: Arc<Notify>,
+}
+
+/// The signing runtime: owns the durable chain head and the adaptive temporal
+/// window, and is the single authority that seals and validates signals.
+#[derive(Debug)]
+pub struct SignalRuntime {
+    committed_hash: std::sync::Mutex<[u8; 32]>,
+    estimator: std::sync::Mutex<TimeoutEstimator>,
+    last_signal_at: std::sync::Mutex<Instant>,
+    commit_hook: Option<CommitHook>,
+}
+
+impl SignalRuntime {
+    pub fn new() -> Self {
+        Self {
+            committed_hash: std::sync::Mutex::new([0u8; 32]),
+            estimator: std::sync::Mutex::new(TimeoutEstimator::new()),
+            last_signal_at: std::sync::Mutex::new(Instant::now()),
+            commit_hook: None,
+        }
+    }
+
+    /// Reconstruct a runtime resuming from a durably committed chain head, as a
+    /// node does after a crash.
+    pub fn recovered_from(head: [u8; 32]) -> Self {
+        Self {
+            committed_hash: std::sync::Mutex::new(head),
+            estimator: std::sync::Mutex::new(TimeoutEstimator::new()),
+            last_signal_at: std::sync::Mutex::new(Instant::now()),
+            commit_hook: None,
+        }
+    }
+
+    /// Construct a runtime whose commit path announces on `arrived` and then
+    /// parks on `release`. The crash-recovery simulation uses this to hold a
+    /// task mid-commit before injecting a fault.
+    pub fn with_commit_hook(arrived: Arc<Notify>, release: Arc<Notify>) -> Self {
+        Self {
+            committed_hash: std::sync::Mutex::new([0u8; 32]),
+            estimator: std::sync::Mutex::new(TimeoutEstimator::new()),
+            last_signal_at: std::sync::Mutex::new(Instant::now()),
+            commit_hook: Some(CommitHook { arrived, release }),
+        }
+    }
+
+    /// The last durably committed chain hash. Recovery resumes from here.
+    pub fn last_committed_hash(&self) -> [u8; 32] {
+        *self.committed_hash.lock().unwrap()
+    }
+
+    /// Minimum plausible `tick_count` for the current network conditions,
+    /// derived from the learned valid-age window so a fabricated future signal
+    /// (too few sequential hashes to have elapsed) is rejected.
+    pub fn tick_floor(&self) -> u64 {
+        self.estimator.lock().unwrap().tick_floor(DEFAULT_TICK_FLOOR)
+    }
+
+    /// Seal a fresh signal onto the current chain head. `tick_count` is sized
+    /// to the wall-clock time actually elapsed since the previous call to
+    /// `generate_signal` returned (converted at the estimator's calibrated
+    /// hash rate), clamped to the plausibility floor and the structural
+    /// ceiling, so the count traces real elapsed time instead of sitting on
+    /// the floor for every signal. The elapsed clock is reset only after this
+    /// call's own sealing work completes, so that work is never counted as
+    /// part of the next call's measured gap.
+    pub fn generate_signal(&self) -> SignalState {
+        let prev_hash = self.last_committed_hash();
+        let floor = self.tick_floor();
+        let elapsed = self.last_signal_at.lock().unwrap().elapsed();
+        let ticks = timeout_estimator::ticks_for_elapsed(elapsed).clamp(floor, proof_of_history::MAX_TICKS);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        let entropy_state = rand::random();
+        let data = vec![0u8; 32];
+        let mut signal = SignalState {
+            timestamp,
+            entropy_state,
+            data,
+            signature: Vec::new(),
+            prev_hash,
+            tick_count: ticks,
+            hash: [0u8; 32],
+        };
+        proof_of_history::seal(&mut signal, prev_hash, ticks);
+        signal.signature = sign_payload(
+            signal.timestamp,
+            signal.entropy_state,
+            &signal.data,
+            &signal.hash,
+        );
+        *self.last_signal_at.lock().unwrap() = Instant::now();
+        signal
+    }
+
+    /// Validate a signal and, on success, durably advance the chain head. A
+    /// signal is accepted only if its signature holds, it links to the current
+    /// head with a plausible tick count, and its age sits inside the adaptive
+    /// temporal window.
+    pub async fn validate_signal(&self, signal: &SignalState) -> bool {
+        let head = self.last_committed_hash();
+        let floor = self.tick_floor();
+        if !validate_signal_against(signal, &head, floor) {
+            return false;
+        }
+        // Temporal window: reject a replayed, stale, or future-dated signal
+        // using the age learned from observed latencies.
+        let Some(age) = signal_age(signal) else {
+            return false;
+        };
+        {
+            let mut estimator = self.estimator.lock().unwrap();
+            if !estimator.accepts(age) || estimator.should_abandon(age) {
+                return false;
+            }
+            // Feed this signal's observed age back into the estimator so the
+            // window keeps tracking live network conditions rather than
+            // sitting on whatever it learned (or the fixed defaults) at
+            // construction time.
+            estimator.observe(age);
+        }
+        // Durability barrier. In production this is the await on the durable
+        // write; the crash-recovery harness injects a hook here so a node can
+        // be killed with the commit genuinely in flight.
+        if let Some(hook) = &self.commit_hook {
+            hook.arrived.notify_one();
+            hook.release.notified().await;
+        }
+        *self.committed_hash.lock().unwrap() = signal.hash;
+        true
+    }
+
+    pub async fn validate_hardware_binding(&self, profile: &HardwareProfile) -> bool {
+        validate_hardware_binding(profile)
+    }
+}
+
+impl Default for SignalRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Age of a signal relative to the current clock, or `None` when the signal is
+/// dated in the future (clock skew or a fabricated timestamp), which is never
+/// temporally valid.
+fn signal_age(signal: &SignalState) -> Option<Duration> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    let age = now.checked_sub(signal.timestamp)?;
+    Some(Duration::from_nanos(age.min(u64::MAX as u128) as u64))
+}
+
+/// Structural + signature + chain validation against a known predecessor and
+/// tick floor. Used by both the runtime and the free-standing fuzz entry point.
+fn validate_signal_against(signal: &SignalState, prev_hash: &[u8; 32], floor: u64) -> bool {
+    let Some(sig) = decode_group_signature(&signal.signature) else {
+        return false;
+    };
+    let payload = signal_payload(
+        signal.timestamp,
+        signal.entropy_state,
+        &signal.data,
+        &signal.hash,
+    );
+    if !frost::verify(&sig, group_key().group_public, &payload) {
+        return false;
+    }
+    signal.tick_count >= floor && proof_of_history::verify(signal, prev_hash, floor)
+}
+
+/// Free-standing validator the fuzz harness drives. A structurally invalid or
+/// unsigned signal must never be reported valid; there is no committed
+/// predecessor, so only a self-consistent genuine signal can pass.
+pub fn validate_signal(signal: &SignalState) -> bool {
+    validate_signal_against(signal, &signal.prev_hash, DEFAULT_TICK_FLOOR)
+}
+
+/// Free-standing hardware-binding validator the fuzz harness drives. A genuine
+/// binding carries an attestation over its fingerprint and feature/capability
+/// set; an all-zero fingerprint, an empty feature set, or an attestation that
+/// does not recompute is never a genuine device, so arbitrary bytes cannot
+/// bind.
+pub fn validate_hardware_binding(profile: &HardwareProfile) -> bool {
+    if profile.fingerprint == [0u8; 32] || profile.features.is_empty() {
+        return false;
+    }
+    profile.attestation == attest_hardware(profile)
+}
@@ -0,0 +1,63 @@
+// security_tests/src/proof_of_history.rs
+use super::SignalState;
+
+/// Upper bound on the sequential-hash count a signal may claim. A hostile
+/// `tick_count` near `u64::MAX` would otherwise spin `evolve` effectively
+/// forever; anything above this is rejected before the loop runs. Also the
+/// cap the runtime clamps a measured-elapsed-time tick count to, so a long
+/// idle gap before `generate_signal` cannot itself demand an unbounded loop.
+pub(crate) const MAX_TICKS: u64 = 1 << 24;
+
+/// Run Blake3 in a sequential loop, seeding from `prev_hash` and mixing in
+/// `payload` after `ticks` iterations. The chain is a tamper-evident ordering
+/// commitment: each signal hashes onto its predecessor, so reordering or
+/// splicing breaks the recomputation. The loop is sequential but NOT a
+/// verifiable-delay function — a producer and a verifier run the identical
+/// work, so the plausibility of the elapsed time is enforced separately by the
+/// caller's `min_ticks` floor, not by the hash alone.
+pub fn evolve(prev_hash: &[u8; 32], ticks: u64, payload: &[u8]) -> [u8; 32] {
+    let mut h = *prev_hash;
+    for _ in 0..ticks {
+        h = *blake3::hash(&h).as_bytes();
+    }
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&h);
+    hasher.update(payload);
+    *hasher.finalize().as_bytes()
+}
+
+/// Payload bytes that are bound into the chain hash for a signal.
+fn payload_of(signal: &SignalState) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&signal.timestamp.to_le_bytes());
+    bytes.extend_from_slice(&signal.entropy_state.to_le_bytes());
+    bytes.extend_from_slice(&signal.data);
+    bytes
+}
+
+/// Recompute the chain for `signal` and confirm it links to `last_hash`.
+///
+/// `min_ticks` is the caller-supplied plausibility floor (see
+/// `SignalRuntime::tick_floor`): a signal claiming fewer sequential hashes than
+/// could have elapsed for the current network conditions is a fabricated
+/// future signal and is rejected. A count above `MAX_TICKS` is rejected too, as
+/// is a `prev_hash` that does not match the last accepted signal or a recorded
+/// `hash` that does not match the recomputed result.
+pub fn verify(signal: &SignalState, last_hash: &[u8; 32], min_ticks: u64) -> bool {
+    if signal.tick_count < min_ticks || signal.tick_count > MAX_TICKS {
+        return false;
+    }
+    if &signal.prev_hash != last_hash {
+        return false;
+    }
+    evolve(&signal.prev_hash, signal.tick_count, &payload_of(signal)) == signal.hash
+}
+
+/// Seal `signal` into the chain following `prev_hash`, running `ticks`
+/// sequential hashes before mixing in the payload. Sets `prev_hash`,
+/// `tick_count`, and the resulting `hash` in place.
+pub fn seal(signal: &mut SignalState, prev_hash: [u8; 32], ticks: u64) {
+    signal.prev_hash = prev_hash;
+    signal.tick_count = ticks;
+    signal.hash = evolve(&prev_hash, ticks, &payload_of(signal));
+}
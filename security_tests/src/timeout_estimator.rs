@@ -0,0 +1,215 @@
+// security_tests/src/timeout_estimator.rs
+use std::time::Duration;
+
+/// Number of fixed-width buckets in the latency histogram.
+const BUCKET_COUNT: usize = 64;
+
+/// Width of a single histogram bucket.
+const BUCKET_WIDTH: Duration = Duration::from_millis(5);
+
+/// Minimum number of samples required before the learned window is trusted;
+/// until then `TimeoutEstimator` returns the conservative fixed defaults.
+const MIN_SAMPLES: u64 = 32;
+
+/// Quantile used to derive the "valid age" cutoff.
+const VALID_QUANTILE: f64 = 0.80;
+
+/// Survival probability at which the Pareto tail is considered exhausted and
+/// a signal must be abandoned.
+const ABANDON_SURVIVAL: f64 = 1.0e-3;
+
+/// Multiplicative decay applied to every bucket on each new sample so the
+/// window tracks changing network conditions rather than averaging forever.
+const DECAY: f64 = 0.999;
+
+/// Conservative fixed defaults used before `MIN_SAMPLES` have been observed.
+const DEFAULT_VALID_AGE: Duration = Duration::from_millis(150);
+const DEFAULT_ABANDON: Duration = Duration::from_secs(2);
+
+/// Lower/upper clamps keeping a pathological sample stream from producing a
+/// window that accepts replays or rejects every honest signal.
+const MIN_VALID_AGE: Duration = Duration::from_millis(20);
+const MAX_VALID_AGE: Duration = Duration::from_secs(1);
+const MIN_ABANDON: Duration = Duration::from_millis(200);
+const MAX_ABANDON: Duration = Duration::from_secs(10);
+
+/// Calibrated sequential Blake3 rate for this host (hashes/second), used to
+/// convert a span of wall-clock time into a plausible sequential-hash count.
+const HASHES_PER_SEC: f64 = 1.0e6;
+
+/// Sequential-hash count a host running at the calibrated rate would perform
+/// over `elapsed`, clamped to `MAX_VALID_AGE` so a long idle gap between
+/// signals cannot itself demand a multi-second sequential-hashing loop at
+/// validation time. Lets a caller size a signal's `tick_count` to time that
+/// actually passed, rather than a fixed floor, so the count traces real
+/// elapsed time between signals within that bound.
+pub fn ticks_for_elapsed(elapsed: Duration) -> u64 {
+    (elapsed.min(MAX_VALID_AGE).as_secs_f64() * HASHES_PER_SEC) as u64
+}
+
+/// Learns the acceptable signal-age window from observed end-to-end
+/// propagation+validation latencies instead of hard-coded skew constants.
+///
+/// The estimator keeps a decaying, fixed-width histogram. The "valid age"
+/// cutoff is the upper edge of the bucket that first reaches `VALID_QUANTILE`
+/// of the mass; the hard-reject threshold fits a Pareto tail to the samples
+/// above that edge and returns the age at which survival drops below
+/// `ABANDON_SURVIVAL`.
+#[derive(Debug, Clone)]
+pub struct TimeoutEstimator {
+    buckets: [f64; BUCKET_COUNT],
+    total: f64,
+    samples: u64,
+}
+
+impl TimeoutEstimator {
+    pub fn new() -> Self {
+        Self {
+            buckets: [0.0; BUCKET_COUNT],
+            total: 0.0,
+            samples: 0,
+        }
+    }
+
+    /// Record one observed signal latency, decaying the existing mass first.
+    pub fn observe(&mut self, latency: Duration) {
+        self.total = 0.0;
+        for bucket in &mut self.buckets {
+            *bucket *= DECAY;
+            self.total += *bucket;
+        }
+
+        let idx = (latency.as_nanos() / BUCKET_WIDTH.as_nanos())
+            .min(BUCKET_COUNT as u128 - 1) as usize;
+        self.buckets[idx] += 1.0;
+        self.total += 1.0;
+        self.samples += 1;
+    }
+
+    /// Upper edge of a bucket index.
+    fn bucket_edge(idx: usize) -> Duration {
+        BUCKET_WIDTH * (idx as u32 + 1)
+    }
+
+    /// Age past which a signal is treated as stale (quantile cutoff).
+    pub fn valid_age(&self) -> Duration {
+        if self.samples < MIN_SAMPLES || self.total <= 0.0 {
+            return DEFAULT_VALID_AGE;
+        }
+
+        let target = self.total * VALID_QUANTILE;
+        let mut acc = 0.0;
+        for idx in 0..BUCKET_COUNT {
+            acc += self.buckets[idx];
+            if acc >= target {
+                return Self::bucket_edge(idx).clamp(MIN_VALID_AGE, MAX_VALID_AGE);
+            }
+        }
+        MAX_VALID_AGE
+    }
+
+    /// Age past which a signal is abandoned outright, derived from a Pareto
+    /// tail fit to the right side of the histogram.
+    pub fn abandon_threshold(&self) -> Duration {
+        if self.samples < MIN_SAMPLES || self.total <= 0.0 {
+            return DEFAULT_ABANDON;
+        }
+
+        // Scale `x_m` is the quantile edge; anything at or below it is the body.
+        let x_m = self.valid_age().as_secs_f64();
+        if x_m <= 0.0 {
+            return DEFAULT_ABANDON;
+        }
+
+        // Shape `alpha` from the mean of log-ratios of samples above `x_m`.
+        let mut weight = 0.0;
+        let mut log_sum = 0.0;
+        for idx in 0..BUCKET_COUNT {
+            let edge = Self::bucket_edge(idx).as_secs_f64();
+            if edge > x_m && self.buckets[idx] > 0.0 {
+                weight += self.buckets[idx];
+                log_sum += self.buckets[idx] * (edge / x_m).ln();
+            }
+        }
+
+        if weight <= 0.0 || log_sum <= 0.0 {
+            return DEFAULT_ABANDON;
+        }
+        let alpha = weight / log_sum;
+
+        // Survival S(x) = (x_m / x)^alpha; solve S(x) = ABANDON_SURVIVAL.
+        let x = x_m * ABANDON_SURVIVAL.powf(-1.0 / alpha);
+        Duration::from_secs_f64(x).clamp(MIN_ABANDON, MAX_ABANDON)
+    }
+
+    /// Minimum plausible sequential-hash count for a signal, derived from the
+    /// learned valid-age window: `hashes_per_second * valid_age`. A fabricated
+    /// future signal cannot have run this many sequential Blake3 iterations in
+    /// the elapsed wall-clock time, so a lower count is rejected. Falls back to
+    /// `default_floor` until the estimator has warmed up.
+    pub fn tick_floor(&self, default_floor: u64) -> u64 {
+        if self.samples < MIN_SAMPLES {
+            return default_floor;
+        }
+        ticks_for_elapsed(self.valid_age()).max(1)
+    }
+
+    /// Convenience predicate used by `validate_signal`: a signal whose measured
+    /// age sits inside the learned window is temporally acceptable.
+    pub fn accepts(&self, age: Duration) -> bool {
+        age <= self.valid_age()
+    }
+
+    /// Whether a signal is so old it must be abandoned rather than retried.
+    pub fn should_abandon(&self, age: Duration) -> bool {
+        age >= self.abandon_threshold()
+    }
+}
+
+impl Default for TimeoutEstimator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_defaults_before_warmup() {
+        let est = TimeoutEstimator::new();
+        assert_eq!(est.valid_age(), DEFAULT_VALID_AGE);
+        assert_eq!(est.abandon_threshold(), DEFAULT_ABANDON);
+    }
+
+    #[test]
+    fn learns_window_from_observed_latencies() {
+        let mut est = TimeoutEstimator::new();
+        for _ in 0..200 {
+            est.observe(Duration::from_millis(30));
+        }
+        // Cutoff tracks the observed body and stays inside the clamps.
+        let valid = est.valid_age();
+        assert!(valid >= MIN_VALID_AGE && valid <= MAX_VALID_AGE);
+        assert!(est.accepts(Duration::from_millis(30)));
+        assert!(est.abandon_threshold() > valid);
+    }
+
+    #[test]
+    fn ticks_for_elapsed_scales_with_duration() {
+        let short = ticks_for_elapsed(Duration::from_millis(1));
+        let long = ticks_for_elapsed(Duration::from_millis(100));
+        assert!(long > short);
+    }
+
+    #[test]
+    fn rejects_skewed_ages_outside_window() {
+        let mut est = TimeoutEstimator::new();
+        for _ in 0..200 {
+            est.observe(Duration::from_millis(25));
+        }
+        assert!(!est.accepts(Duration::from_secs(5)));
+        assert!(est.should_abandon(MAX_ABANDON));
+    }
+}